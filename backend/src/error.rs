@@ -12,6 +12,9 @@ pub enum ApiError {
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
 
+    #[error("Method not allowed")]
+    MethodNotAllowed(&'static [&'static str]),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -22,16 +25,21 @@ impl ApiError {
             ApiError::NotFound => (404, self.to_string()),
             ApiError::BadRequest(_) => (400, self.to_string()),
             ApiError::Unauthorized(_) => (401, self.to_string()),
+            ApiError::MethodNotAllowed(_) => (405, self.to_string()),
             ApiError::Internal(_) => (500, "Internal server error".to_string()),
         };
 
-        let body = serde_json::json!({ "error": message }).to_string();
-
-        Response::builder()
+        let mut body = serde_json::json!({ "error": message });
+        let mut builder = Response::builder()
             .status(status)
-            .header("Content-Type", "application/json")
-            .body(Body::from(body))
-            .unwrap()
+            .header("Content-Type", "application/json");
+        if let ApiError::MethodNotAllowed(allowed) = &self {
+            body["allowed"] = serde_json::json!(allowed);
+            builder = builder.header("Allow", allowed.join(", "));
+        }
+        let body = body.to_string();
+
+        builder.body(Body::from(body)).unwrap()
     }
 }
 
@@ -47,3 +55,26 @@ impl From<serde_json::Error> for ApiError {
         ApiError::BadRequest(format!("Invalid JSON: {e}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn method_not_allowed_sets_status_allow_header_and_body() {
+        let resp = ApiError::MethodNotAllowed(&["GET", "POST"]).into_response();
+
+        assert_eq!(resp.status().as_u16(), 405);
+        assert_eq!(
+            resp.headers().get("Allow").unwrap().to_str().unwrap(),
+            "GET, POST"
+        );
+
+        let Body::Text(body) = resp.body() else {
+            panic!("expected text body");
+        };
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["error"], "Method not allowed");
+        assert_eq!(body["allowed"], serde_json::json!(["GET", "POST"]));
+    }
+}
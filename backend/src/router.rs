@@ -38,21 +38,77 @@ async fn route_inner(
 
     let (family_id, user_id) = extract_claims(&req)?;
 
-    match (method, path) {
-        ("GET", "/todos") => handlers::list_todos(db, &family_id).await,
-        ("POST", "/todos") => handlers::create_todo(req, db, &family_id, &user_id).await,
-        (_, p) if p.starts_with("/todos/") => {
-            let todo_id = &p[7..];
-            if todo_id.is_empty() {
-                return Err(ApiError::BadRequest("Missing todo ID".to_string()));
-            }
-            match method {
-                "PATCH" => handlers::update_todo(req, db, &family_id, todo_id).await,
-                "DELETE" => handlers::delete_todo(db, &family_id, todo_id).await,
-                _ => Err(ApiError::NotFound),
-            }
+    let (handler, todo_id) = resolve(method, path)?;
+
+    match handler {
+        Handler::ListTodos => handlers::list_todos(db, &family_id).await,
+        Handler::CreateTodo => handlers::create_todo(req, db, &family_id, &user_id).await,
+        Handler::UpdateTodo => handlers::update_todo(req, db, &family_id, todo_id).await,
+        Handler::DeleteTodo => handlers::delete_todo(db, &family_id, todo_id).await,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Handler {
+    ListTodos,
+    CreateTodo,
+    UpdateTodo,
+    DeleteTodo,
+}
+
+// Path pattern → allowed methods. `{id}` matches a single path segment.
+// OPTIONS is answered for every path before routing, so it is listed too.
+const ROUTES: &[(&str, &[&str])] = &[
+    ("/todos", &["GET", "POST", "OPTIONS"]),
+    ("/todos/{id}", &["PATCH", "DELETE", "OPTIONS"]),
+];
+
+fn handler_for(pattern: &str, method: &str) -> Option<Handler> {
+    match (pattern, method) {
+        ("/todos", "GET") => Some(Handler::ListTodos),
+        ("/todos", "POST") => Some(Handler::CreateTodo),
+        ("/todos/{id}", "PATCH") => Some(Handler::UpdateTodo),
+        ("/todos/{id}", "DELETE") => Some(Handler::DeleteTodo),
+        _ => None,
+    }
+}
+
+fn resolve<'a>(method: &str, path: &'a str) -> Result<(Handler, &'a str), ApiError> {
+    let (pattern, allowed, todo_id) = match_route(path).ok_or(ApiError::NotFound)?;
+    if !allowed.contains(&method) {
+        return Err(ApiError::MethodNotAllowed(allowed));
+    }
+    if todo_id == Some("") {
+        return Err(ApiError::BadRequest("Missing todo ID".to_string()));
+    }
+
+    // A method listed in ROUTES without a handler is a programming error,
+    // not a missing resource.
+    let handler = handler_for(pattern, method).ok_or_else(|| {
+        ApiError::Internal(format!("No handler registered for {method} {pattern}"))
+    })?;
+
+    Ok((handler, todo_id.unwrap_or_default()))
+}
+
+fn match_route(path: &str) -> Option<(&'static str, &'static [&'static str], Option<&str>)> {
+    ROUTES.iter().find_map(|&(pattern, allowed)| {
+        match_pattern(pattern, path).map(|param| (pattern, allowed, param))
+    })
+}
+
+fn match_pattern<'a>(pattern: &str, path: &'a str) -> Option<Option<&'a str>> {
+    let mut pattern_segments = pattern.split('/');
+    let mut path_segments = path.split('/');
+    let mut param = None;
+
+    loop {
+        match (pattern_segments.next(), path_segments.next()) {
+            (None, None) => return Some(param),
+            (Some("{id}"), Some(segment)) => param = Some(segment),
+            (Some(expected), Some(segment)) if expected == segment => {}
+            _ => return None,
         }
-        _ => Err(ApiError::NotFound),
     }
 }
 
@@ -94,3 +150,96 @@ fn add_cors_headers(resp: &mut Response<Body>) {
         "Content-Type,Authorization".parse().unwrap(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_route_collection_has_no_param() {
+        let (pattern, allowed, param) = match_route("/todos").unwrap();
+        assert_eq!(pattern, "/todos");
+        assert_eq!(allowed, &["GET", "POST", "OPTIONS"]);
+        assert_eq!(param, None);
+    }
+
+    #[test]
+    fn match_route_captures_todo_id() {
+        let (pattern, allowed, param) = match_route("/todos/abc").unwrap();
+        assert_eq!(pattern, "/todos/{id}");
+        assert_eq!(allowed, &["PATCH", "DELETE", "OPTIONS"]);
+        assert_eq!(param, Some("abc"));
+    }
+
+    #[test]
+    fn match_route_captures_empty_todo_id() {
+        let (_, _, param) = match_route("/todos/").unwrap();
+        assert_eq!(param, Some(""));
+    }
+
+    #[test]
+    fn match_route_rejects_unknown_paths() {
+        assert!(match_route("/todos/a/b").is_none());
+        assert!(match_route("/todos/abc/").is_none());
+        assert!(match_route("/other").is_none());
+    }
+
+    #[test]
+    fn resolve_dispatches_allowed_methods() {
+        assert_eq!(resolve("GET", "/todos").unwrap(), (Handler::ListTodos, ""));
+        assert_eq!(
+            resolve("POST", "/todos").unwrap(),
+            (Handler::CreateTodo, "")
+        );
+        assert_eq!(
+            resolve("PATCH", "/todos/abc").unwrap(),
+            (Handler::UpdateTodo, "abc")
+        );
+        assert_eq!(
+            resolve("DELETE", "/todos/abc").unwrap(),
+            (Handler::DeleteTodo, "abc")
+        );
+    }
+
+    #[test]
+    fn resolve_rejects_disallowed_method_with_405() {
+        match resolve("PUT", "/todos") {
+            Err(ApiError::MethodNotAllowed(allowed)) => {
+                assert_eq!(allowed, &["GET", "POST", "OPTIONS"])
+            }
+            other => panic!("expected MethodNotAllowed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn resolve_checks_method_before_empty_id() {
+        assert!(matches!(
+            resolve("PUT", "/todos/"),
+            Err(ApiError::MethodNotAllowed(_))
+        ));
+        assert!(matches!(
+            resolve("PATCH", "/todos/"),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn resolve_returns_404_for_nested_todo_path() {
+        assert!(matches!(
+            resolve("PATCH", "/todos/a/b"),
+            Err(ApiError::NotFound)
+        ));
+    }
+
+    #[test]
+    fn every_routed_method_has_a_handler() {
+        for &(pattern, allowed) in ROUTES {
+            for &method in allowed.iter().filter(|&&m| m != "OPTIONS") {
+                assert!(
+                    handler_for(pattern, method).is_some(),
+                    "{method} {pattern} has no handler"
+                );
+            }
+        }
+    }
+}